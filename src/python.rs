@@ -7,6 +7,7 @@ use pyo3::once_cell::GILOnceCell;
 use self::density::PyDensityGradient;
 use self::elements::{elements as elements_fun, PyAtomicElement};
 use self::geometry::{
+    PyCompositeGeometry,
     PyExternalGeometry,
     PyGeometrySector,
     PySimpleGeometry,
@@ -27,7 +28,14 @@ use self::materials::{
 use self::rand::PyRandomStream;
 use process_path::get_dylib_path;
 use self::process::{PyComptonProcess, PyRayleighProcess};
-use self::transport::{PyTransportEngine, PyTransportSettings, PyTransportStatus};
+use self::stats::{profile as profile_fun, PyProfile};
+use self::transport::{
+    PyTransportEngine,
+    PyTransportIterator,
+    PyTransportJob,
+    PyTransportSettings,
+    PyTransportStatus,
+};
 use self::transport::{states as states_fun};
 use std::path::PathBuf;
 
@@ -38,6 +46,7 @@ mod materials;
 mod numpy;
 mod rand;
 mod process;
+mod stats;
 mod transport;
 
 
@@ -133,9 +142,11 @@ fn goupil(py: Python, module: &PyModule) -> PyResult<()> {
 
     // Register attributes.
     module.add("PREFIX", prefix(py)?)?;
+    module.add("TRANSPORT_STATUS", self::transport::status_mapping(py)?)?;
 
     // Register class object(s).
     module.add_class::<PyAtomicElement>()?;
+    module.add_class::<PyCompositeGeometry>()?;
     module.add_class::<PyComptonProcess>()?;
     module.add_class::<PyCrossSection>()?;
     module.add_class::<PyDensityGradient>()?;
@@ -148,6 +159,7 @@ fn goupil(py: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<PyMaterialDefinition>()?;
     module.add_class::<PyMaterialRecord>()?;
     module.add_class::<PyMaterialRegistry>()?;
+    module.add_class::<PyProfile>()?;
     module.add_class::<PySimpleGeometry>()?;
     module.add_class::<PyStratifiedGeometry>()?;
     module.add_class::<PyRandomStream>()?;
@@ -155,11 +167,14 @@ fn goupil(py: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<PyTopographyMap>()?;
     module.add_class::<PyTopographySurface>()?;
     module.add_class::<PyTransportEngine>()?;
+    module.add_class::<PyTransportIterator>()?;
+    module.add_class::<PyTransportJob>()?;
     module.add_class::<PyTransportSettings>()?;
     module.add_class::<PyTransportStatus>()?;
 
     // Register function(s).
     module.add_function(wrap_pyfunction!(elements_fun, module)?)?;
+    module.add_function(wrap_pyfunction!(profile_fun, module)?)?;
     module.add_function(wrap_pyfunction!(states_fun, module)?)?;
 
     Ok(())
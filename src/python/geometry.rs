@@ -2,8 +2,9 @@ use anyhow::Result;
 use crate::numerics::Float;
 use crate::transport::{
     density::DensityModel,
-    geometry::{ExternalGeometry, ExternalTracer, GeometryDefinition, GeometryTracer,
-               SimpleGeometry, StratifiedGeometry, TopographyData, TopographyMap},
+    geometry::{CompositeGeometry, CompositeTracer, ExternalGeometry, ExternalTracer,
+               GeometryDefinition, GeometryMember, GeometryTracer, SimpleGeometry,
+               StratifiedGeometry, TopographyData, TopographyMap},
     PhotonState,
 };
 use pyo3::prelude::*;
@@ -12,7 +13,7 @@ use std::rc::Rc;
 use super::ctrlc_catched;
 use super::macros::value_error;
 use super::materials::PyMaterialDefinition;
-use super::numpy::{ArrayOrFloat, PyArray, PyArrayFlags};
+use super::numpy::{ArrayOrFloat, Broadcast, PyArray, PyArrayFlags};
 use super::transport::CState;
 
 
@@ -180,6 +181,7 @@ impl PyExternalGeometry {
         states: &PyArray<CState>,
         lengths: Option<ArrayOrFloat>,
         density: Option<bool>,
+        records: Option<bool>,
     ) -> Result<PyObject> {
         let n = states.size();
         if let Some(lengths) = lengths.as_ref() {
@@ -194,15 +196,74 @@ impl PyExternalGeometry {
             }
         }
 
+        let py = states.py();
+        let density = density.unwrap_or(false);
+        let records = records.unwrap_or(false);
+        let mut tracer = ExternalTracer::new(&self.inner)?;
+        let mut k: usize = 0;
+
+        if records {
+            let mut segments: Vec<CSegment> = Vec::new();
+            let mut offsets: Vec<usize> = Vec::with_capacity(n + 1);
+            offsets.push(0);
+            for i in 0..n {
+                let state: PhotonState = states.get(i)?.into();
+                tracer.reset(state.position, state.direction)?;
+                let mut length = match lengths.as_ref() {
+                    None => Float::INFINITY,
+                    Some(lengths) => match &lengths {
+                        ArrayOrFloat::Array(lengths) => lengths.get(i)?,
+                        ArrayOrFloat::Float(lengths) => *lengths,
+                    },
+                };
+                loop {
+                    match tracer.sector() {
+                        None => break,
+                        Some(sector) => {
+                            let step_length = tracer.trace(length)?;
+                            let position = tracer.position();
+                            let column_depth = if density {
+                                let model = &self.inner.sectors[sector].density;
+                                model.column_depth(position, state.direction, step_length)
+                            } else {
+                                0.0
+                            };
+                            segments.push(CSegment {
+                                sector,
+                                position: position.into(),
+                                length: step_length,
+                                column_depth,
+                            });
+                            if lengths.is_some() {
+                                length -= step_length;
+                                if length <= 0.0 { break }
+                            }
+                            tracer.update(step_length, state.direction)?;
+                        },
+                    }
+                    k += 1;
+                    if k == 1000 { // Check for a Ctrl+C interrupt, catched by Python.
+                        ctrlc_catched()?;
+                        k = 0;
+                    }
+                }
+                offsets.push(segments.len());
+            }
+            let segments = PyArray::<CSegment>::from_iter(
+                py, &[segments.len()], segments.into_iter()
+            )?;
+            let offsets = PyArray::<usize>::from_iter(
+                py, &[offsets.len()], offsets.into_iter()
+            )?;
+            let result = PyTuple::new(py, [segments.into_py(py), offsets.into_py(py)]);
+            return Ok(result.into_py(py));
+        }
+
         let mut shape = states.shape();
         let m = self.inner.sectors().len();
         shape.push(m);
-        let py = states.py();
         let result = PyArray::<Float>::empty(py, &shape)?;
 
-        let density = density.unwrap_or(false);
-        let mut tracer = ExternalTracer::new(&self.inner)?;
-        let mut k: usize = 0;
         for i in 0..n {
             let state: PhotonState = states.get(i)?.into();
             let mut grammages: Vec<Float> = vec![0.0; m];
@@ -241,6 +302,8 @@ impl PyExternalGeometry {
                     k = 0;
                 }
             }
+            // The result has the states' shape with a trailing axis of length m, so this photon's
+            // per-sector grammages start at flat offset i * m, whatever the states' dimension.
             let j0 = i * m;
             for j in 0..m {
                 result.set(j0 + j, grammages[j])?;
@@ -370,10 +433,24 @@ impl PyTopographyMap {
             xrange[0], xrange[1], shape[1], yrange[0], yrange[1], shape[0]
         );
         if let Some(z) = z {
-            for i in 0..shape[0] {
-                for j in 0..shape[1] {
-                    let k = i * shape[1] + j;
-                    map.z[(i, j)] = z.get(k)?;
+            if z.shape().len() == 2 {
+                // A genuine 2D z-array: read it through an `ndarray` view over its actual shape
+                // and strides, so a transposed or sliced (non-contiguous) input is read
+                // correctly, rather than assuming a flat, C-contiguous raster.
+                let view = z.as_array()?;
+                for i in 0..shape[0] {
+                    for j in 0..shape[1] {
+                        map.z[(i, j)] = view[[i, j]];
+                    }
+                }
+            } else {
+                // A flat z-array paired with an explicit `shape`: it is the raster itself, in
+                // row-major order.
+                for i in 0..shape[0] {
+                    for j in 0..shape[1] {
+                        let k = i * shape[1] + j;
+                        map.z[(i, j)] = z.get(k)?;
+                    }
                 }
             }
         }
@@ -413,11 +490,156 @@ impl PyTopographyMap {
         Self::__add__(lhs, -rhs)
     }
 
-    fn __call__(&self, x: Float, y: Float) -> Option<Float> { // XXX vectorise and fill
-        self.inner.z(x, y)
+    // Scalar calls preserve the pre-vectorisation `Optional[float]` contract (`None` out of
+    // range, unless `fill` is given); only actual array inputs go through the vectorised,
+    // NaN-filled ndarray path.
+    #[pyo3(signature = (x, y, fill=None, interpolation=None))]
+    fn __call__(
+        &self,
+        py: Python,
+        x: ArrayOrFloat,
+        y: ArrayOrFloat,
+        fill: Option<Float>,
+        interpolation: Option<&str>,
+    ) -> Result<PyObject> {
+        let interpolation = Interpolation::parse(interpolation)?;
+        let xs: &PyArray<Float> = self.x.extract(py)?;
+        let ys: &PyArray<Float> = self.y.extract(py)?;
+        let z: &PyArray<Float> = self.z.extract(py)?;
+
+        if let (ArrayOrFloat::Float(xi), ArrayOrFloat::Float(yi)) = (&x, &y) {
+            let value = sample_topography(xs, ys, z, *xi, *yi, interpolation)?
+                .or(fill);
+            return Ok(value.into_py(py));
+        }
+
+        let fill = fill.unwrap_or(Float::NAN);
+        let broadcast = Broadcast::new(&[&x.shape(), &y.shape()])?;
+        let result = PyArray::<Float>::empty(py, broadcast.shape())?;
+        for index in 0..broadcast.size() {
+            let xi = x.get_broadcast(&broadcast, 0, index)?;
+            let yi = y.get_broadcast(&broadcast, 1, index)?;
+            let value = sample_topography(xs, ys, z, xi, yi, interpolation)?
+                .unwrap_or(fill);
+            result.set(index, value)?;
+        }
+        let result: &PyAny = result;
+        Ok(result.into_py(py))
+    }
+}
+
+
+// ===============================================================================================
+// Vectorised topography sampling, shared by `TopographyMap.__call__` and
+// `TopographyOffset.__call__`.
+// ===============================================================================================
+
+#[derive(Clone, Copy)]
+enum Interpolation {
+    Nearest,
+    Bilinear,
+    Bicubic,
+}
+
+impl Interpolation {
+    fn parse(name: Option<&str>) -> Result<Self> {
+        let result = match name.unwrap_or("bilinear") {
+            "nearest" => Self::Nearest,
+            "bilinear" => Self::Bilinear,
+            "bicubic" => Self::Bicubic,
+            other => value_error!(
+                "bad interpolation (expected 'nearest', 'bilinear' or 'bicubic', found '{}')",
+                other,
+            ),
+        };
+        Ok(result)
+    }
+}
+
+// Catmull-Rom cubic convolution kernel, applied separably in x then y for bicubic sampling.
+fn cubic_kernel(t: Float) -> Float {
+    let t = t.abs();
+    if t <= 1.0 {
+        1.5 * t * t * t - 2.5 * t * t + 1.0
+    } else if t <= 2.0 {
+        -0.5 * t * t * t + 2.5 * t * t - 4.0 * t + 2.0
+    } else {
+        0.0
     }
 }
 
+// Sample the `(xs, ys, z)` grid at `(x, y)`, returning `None` if the point falls outside
+// `[xs[0], xs[-1]] x [ys[0], ys[-1]]`.
+fn sample_topography(
+    xs: &PyArray<Float>,
+    ys: &PyArray<Float>,
+    z: &PyArray<Float>,
+    x: Float,
+    y: Float,
+    interpolation: Interpolation,
+) -> Result<Option<Float>> {
+    let nx = xs.size();
+    let ny = ys.size();
+    if nx < 2 || ny < 2 {
+        return Ok(None)
+    }
+
+    let x0 = xs.get(0)?;
+    let x1 = xs.get(nx - 1)?;
+    let y0 = ys.get(0)?;
+    let y1 = ys.get(ny - 1)?;
+    if x < x0 || x > x1 || y < y0 || y > y1 {
+        return Ok(None)
+    }
+
+    let dx = (x1 - x0) / ((nx - 1) as Float);
+    let dy = (y1 - y0) / ((ny - 1) as Float);
+    let fx = (x - x0) / dx;
+    let fy = (y - y0) / dy;
+    let i = (fx.floor() as isize).clamp(0, (nx - 2) as isize) as usize;
+    let j = (fy.floor() as isize).clamp(0, (ny - 2) as isize) as usize;
+    let u = fx - (i as Float);
+    let v = fy - (j as Float);
+
+    let at = |row: isize, col: isize| -> Result<Float> {
+        let row = row.clamp(0, (ny - 1) as isize) as usize;
+        let col = col.clamp(0, (nx - 1) as isize) as usize;
+        z.get(row * nx + col)
+    };
+
+    let value = match interpolation {
+        Interpolation::Nearest => {
+            let col = if u < 0.5 { i } else { i + 1 };
+            let row = if v < 0.5 { j } else { j + 1 };
+            at(row as isize, col as isize)?
+        },
+        Interpolation::Bilinear => {
+            let z00 = at(j as isize, i as isize)?;
+            let z01 = at(j as isize, i as isize + 1)?;
+            let z10 = at(j as isize + 1, i as isize)?;
+            let z11 = at(j as isize + 1, i as isize + 1)?;
+            z00 * (1.0 - u) * (1.0 - v)
+                + z01 * u * (1.0 - v)
+                + z10 * (1.0 - u) * v
+                + z11 * u * v
+        },
+        Interpolation::Bicubic => {
+            let mut value = 0.0;
+            for dj in -1..=2 {
+                let wj = cubic_kernel((dj as Float) - v);
+                let mut row_value = 0.0;
+                for di in -1..=2 {
+                    let wi = cubic_kernel((di as Float) - u);
+                    row_value += wi * at(j as isize + dj, i as isize + di)?;
+                }
+                value += wj * row_value;
+            }
+            value
+        },
+    };
+    Ok(Some(value))
+}
+
 
 // ===============================================================================================
 // Python wrapper for a topography map offset.
@@ -464,7 +686,45 @@ impl PyTopographyOffset {
         Self::__add__(lhs, -rhs)
     }
 
-    // XXX Add call method?
+    // Scalar calls preserve the pre-vectorisation `Optional[float]` contract (`None` out of
+    // range, unless `fill` is given); only actual array inputs go through the vectorised,
+    // NaN-filled ndarray path.
+    #[pyo3(signature = (x, y, fill=None, interpolation=None))]
+    fn __call__(
+        &self,
+        py: Python,
+        x: ArrayOrFloat,
+        y: ArrayOrFloat,
+        fill: Option<Float>,
+        interpolation: Option<&str>,
+    ) -> Result<PyObject> {
+        let interpolation = Interpolation::parse(interpolation)?;
+        let map: PyRef<PyTopographyMap> = self.map.extract(py)?;
+        let xs: &PyArray<Float> = map.x.extract(py)?;
+        let ys: &PyArray<Float> = map.y.extract(py)?;
+        let z: &PyArray<Float> = map.z.extract(py)?;
+
+        if let (ArrayOrFloat::Float(xi), ArrayOrFloat::Float(yi)) = (&x, &y) {
+            let value = sample_topography(xs, ys, z, *xi, *yi, interpolation)?
+                .map(|v| v + self.offset)
+                .or(fill);
+            return Ok(value.into_py(py));
+        }
+
+        let fill = fill.unwrap_or(Float::NAN);
+        let broadcast = Broadcast::new(&[&x.shape(), &y.shape()])?;
+        let result = PyArray::<Float>::empty(py, broadcast.shape())?;
+        for index in 0..broadcast.size() {
+            let xi = x.get_broadcast(&broadcast, 0, index)?;
+            let yi = y.get_broadcast(&broadcast, 1, index)?;
+            let value = sample_topography(xs, ys, z, xi, yi, interpolation)?
+                .map(|v| v + self.offset)
+                .unwrap_or(fill);
+            result.set(index, value)?;
+        }
+        let result: &PyAny = result;
+        Ok(result.into_py(py))
+    }
 }
 
 #[derive(FromPyObject)]
@@ -632,21 +892,261 @@ impl<'py> From<PyTopographyInterface<'py>> for Vec<TopographyData> {
 }
 
 
+// ===============================================================================================
+// Python wrapper for a composite geometry object, nesting several (sub-)geometries into a
+// single world, e.g. a meshed ExternalGeometry detector embedded inside an outer
+// StratifiedGeometry atmosphere/ground model.
+// ===============================================================================================
+
+#[pyclass(name = "CompositeGeometry", module = "goupil")]
+pub struct PyCompositeGeometry {
+    pub inner: CompositeGeometry,
+
+    #[pyo3(get)]
+    materials: PyObject,
+    #[pyo3(get)]
+    members: PyObject,
+    #[pyo3(get)]
+    sectors: PyObject,
+}
+
+unsafe impl Send for PyCompositeGeometry {}
+
+#[pymethods]
+impl PyCompositeGeometry {
+    #[new]
+    #[pyo3(signature = (*args))]
+    fn new(py: Python, args: &PyTuple) -> Result<Self> {
+        if args.is_empty() {
+            value_error!(
+                "bad number of argument(s) (expected one or more geometries, found zero)"
+            )
+        }
+
+        let mut inner_members = Vec::<GeometryMember>::with_capacity(args.len());
+        let mut members = Vec::<PyObject>::with_capacity(args.len());
+        for arg in args.iter() {
+            let definition: PyGeometryDefinition = arg.extract()?;
+            let member = match &definition {
+                PyGeometryDefinition::External(external) =>
+                    GeometryMember::External(external.borrow(py).inner.clone()),
+                PyGeometryDefinition::Simple(simple) =>
+                    GeometryMember::Simple(simple.borrow(py).0.clone()),
+                PyGeometryDefinition::Stratified(stratified) =>
+                    GeometryMember::Stratified(stratified.borrow(py).inner.clone()),
+                PyGeometryDefinition::Composite(_) => value_error!(
+                    "bad member (nesting a 'CompositeGeometry' inside another is not supported)"
+                ),
+            };
+            inner_members.push(member);
+            members.push(definition.into_py(py));
+        }
+
+        // Build the inner geometry, assigning a consistent global sector numbering across
+        // members (member boundaries are picked nearest-first by the tracer, at each step).
+        let inner = CompositeGeometry::new(inner_members)?;
+
+        // Export materials and sectors, exactly as the other geometry wrappers do.
+        let materials: &PyTuple = {
+            let mut materials = Vec::<PyObject>::with_capacity(inner.materials().len());
+            for material in inner.materials().iter() {
+                let material = PyMaterialDefinition(material.clone());
+                materials.push(material.into_py(py));
+            }
+            PyTuple::new(py, materials)
+        };
+        let sectors: PyObject = {
+            let sectors: std::result::Result<Vec<_>, _> = inner
+                .sectors()
+                .iter()
+                .map(|sector| Py::new(py, PyGeometrySector {
+                    material: (&materials[sector.material]).into_py(py),
+                    density: sector.density.into_py(py),
+                    description: sector.description
+                        .as_ref()
+                        .map(|description| description.to_string()),
+                }))
+                .collect();
+            PyTuple::new(py, sectors?).into_py(py)
+        };
+        let materials: PyObject = materials.into_py(py);
+        let members: PyObject = PyTuple::new(py, members).into_py(py);
+
+        Ok(Self { inner, materials, members, sectors })
+    }
+
+    fn locate(&self, states: &PyArray<CState>) -> Result<PyObject> {
+        let py = states.py();
+        let sectors = PyArray::<usize>::empty(py, &states.shape())?;
+        let mut tracer = CompositeTracer::new(&self.inner)?;
+        let m = self.inner.sectors().len();
+        let n = states.size();
+        for i in 0..n {
+            let state: PhotonState = states.get(i)?.into();
+            tracer.reset(state.position, state.direction)?;
+            let sector = tracer.sector().unwrap_or(m);
+            sectors.set(i, sector)?;
+
+            if i % 1000 == 0 { // Check for a Ctrl+C interrupt, catched by Python.
+                ctrlc_catched()?;
+            }
+        }
+        let sectors: &PyAny = sectors;
+        Ok(sectors.into_py(py))
+    }
+
+    fn trace(
+        &self,
+        states: &PyArray<CState>,
+        lengths: Option<ArrayOrFloat>,
+        density: Option<bool>,
+    ) -> Result<PyObject> {
+        let n = states.size();
+        if let Some(lengths) = lengths.as_ref() {
+            if let ArrayOrFloat::Array(lengths) = &lengths {
+                if lengths.size() != states.size() {
+                    value_error!(
+                        "bad lengths (expected a float or a size {} array, found a size {} array)",
+                        states.size(),
+                        lengths.size(),
+                    )
+                }
+            }
+        }
+
+        let mut shape = states.shape();
+        let m = self.inner.sectors().len();
+        shape.push(m);
+        let py = states.py();
+        let result = PyArray::<Float>::empty(py, &shape)?;
+
+        let density = density.unwrap_or(false);
+        let mut tracer = CompositeTracer::new(&self.inner)?;
+        let mut k: usize = 0;
+        for i in 0..n {
+            let state: PhotonState = states.get(i)?.into();
+            let mut grammages: Vec<Float> = vec![0.0; m];
+            tracer.reset(state.position, state.direction)?;
+            let mut length = match lengths.as_ref() {
+                None => Float::INFINITY,
+                Some(lengths) => match &lengths {
+                    ArrayOrFloat::Array(lengths) => lengths.get(i)?,
+                    ArrayOrFloat::Float(lengths) => *lengths,
+                },
+            };
+            loop {
+                match tracer.sector() {
+                    None => break,
+                    Some(sector) => {
+                        let step_length = tracer.trace(length)?;
+                        if density {
+                            let model = &self.inner.sectors()[sector].density;
+                            let position = tracer.position();
+                            grammages[sector] += model.column_depth(
+                                position, state.direction, step_length
+                            );
+                        } else {
+                            grammages[sector] += step_length;
+                        }
+                        if lengths.is_some() {
+                            length -= step_length;
+                            if length <= 0.0 { break }
+                        }
+                        tracer.update(step_length, state.direction)?;
+                    },
+                }
+                k += 1;
+                if k == 1000 { // Check for a Ctrl+C interrupt, catched by Python.
+                    ctrlc_catched()?;
+                    k = 0;
+                }
+            }
+            // The result has the states' shape with a trailing axis of length m, so this photon's
+            // per-sector grammages start at flat offset i * m, whatever the states' dimension.
+            let j0 = i * m;
+            for j in 0..m {
+                result.set(j0 + j, grammages[j])?;
+            }
+        }
+        let result: &PyAny = result;
+        Ok(result.into_py(py))
+    }
+
+    fn update_material(
+        &mut self,
+        index: usize,
+        material: PyRef<PyMaterialDefinition>
+    ) -> Result<()> {
+        // Update inner state.
+        self.inner.update_material(index, &material.0)?;
+
+        // Update external state.
+        let py = material.py();
+        let materials: &PyTuple = self.materials.extract(py)?;
+        let mut this: PyRefMut<PyMaterialDefinition> = materials[index].extract()?;
+        this.0 = material.0.clone();
+
+        Ok(())
+    }
+
+    fn update_sector(
+        &mut self,
+        py: Python,
+        index: usize,
+        material: Option<usize>,
+        density: Option<DensityModel>,
+    ) -> Result<()> {
+        // Update inner state.
+        self.inner.update_sector(index, material, density.as_ref())?;
+
+        // Update external state.
+        let sectors: &PyTuple = self.sectors.extract(py)?;
+        let mut this: PyRefMut<PyGeometrySector> = sectors[index].extract()?;
+        if let Some(material) = material {
+            let materials: &PyTuple = self.materials.extract(py)?;
+            this.material = materials[material].into_py(py);
+        }
+        if let Some(density) = density.as_ref() {
+            this.density = density.into_py(py);
+        }
+
+        Ok(())
+    }
+}
+
+
 // ===============================================================================================
 // Unresolved geometry definition.
 // ===============================================================================================
 
 #[derive(Clone, FromPyObject)]
 pub enum PyGeometryDefinition {
+    Composite(Py<PyCompositeGeometry>),
     External(Py<PyExternalGeometry>),
     Simple(Py<PySimpleGeometry>),
+    Stratified(Py<PyStratifiedGeometry>),
 }
 
 impl IntoPy<PyObject> for PyGeometryDefinition {
     fn into_py(self, py: Python) -> PyObject {
         match self {
+            Self::Composite(composite) => composite.into_py(py),
             Self::External(external) => external.into_py(py),
             Self::Simple(simple) => simple.into_py(py),
+            Self::Stratified(stratified) => stratified.into_py(py),
         }
     }
 }
+
+
+// ===============================================================================================
+// C representation of a traced geometry segment.
+// ===============================================================================================
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct CSegment {
+    pub sector: usize,
+    pub position: [Float; 3],
+    pub length: Float,
+    pub column_depth: Float,
+}
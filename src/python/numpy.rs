@@ -1,5 +1,7 @@
 use crate::numerics::float::{Float, Float3};
 use crate::physics::materials::electronic::ElectronicShell;
+// Ndarray interface.
+use ndarray::{ArrayView, IxDyn, ShapeBuilder};
 // PyO3 interface.
 use pyo3::conversion::{FromPyObject, IntoPy, ToPyObject};
 use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
@@ -8,13 +10,15 @@ use pyo3::marker::Python;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::{Py, PyErr, PyNativeType, PyObject, PyResult};
 use pyo3::type_object::PyTypeInfo;
-use pyo3::types::{PyAny, PyCapsule, PyModule};
+use pyo3::types::{PyAny, PyCapsule, PyModule, PySlice};
 // Standard library.
 use std::cell::UnsafeCell;
 use std::ffi::{c_char, c_int, c_void};
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
 // Local Python interface.
+use super::geometry::CSegment;
 use super::transport::CState;
 
 
@@ -40,6 +44,7 @@ struct ArrayInterface {
     // Type objects.
     dtype_float: PyObject,
     dtype_int32: PyObject,
+    dtype_segment: PyObject,
     dtype_shell: PyObject,
     dtype_state: PyObject,
     dtype_usize: PyObject,
@@ -135,6 +140,18 @@ pub fn initialise(py: Python) -> PyResult<()> {
         .call1(("i4",))?
         .into_py(py);
 
+    let dtype_segment: PyObject = {
+        let arg: [PyObject; 4] = [
+            ("sector", format!("u{}", std::mem::size_of::<usize>())).into_py(py),
+            ("position", FLOAT_FORMAT, 3).into_py(py),
+            ("length", FLOAT_FORMAT).into_py(py),
+            ("column_depth", FLOAT_FORMAT).into_py(py),
+        ];
+        dtype
+            .call1((arg,))?
+            .into_py(py)
+    };
+
     let dtype_shell: PyObject = dtype
         .call1(([
             ("occupancy", FLOAT_FORMAT),
@@ -182,6 +199,7 @@ pub fn initialise(py: Python) -> PyResult<()> {
         // Type objects.
         dtype_float,
         dtype_int32,
+        dtype_segment,
         dtype_shell,
         dtype_state,
         dtype_usize,
@@ -384,6 +402,35 @@ impl<T> PyArray<T>
 where
     T: Copy + Dtype,
 {
+    /// A zero-copy `ndarray` view over this array's actual shape and strides, so that (unlike
+    /// `get`'s flat indexing) a transposed or otherwise non-contiguous multi-dimensional array is
+    /// read correctly rather than silently misread as if it were a C-contiguous raster.
+    pub fn as_array(&self) -> PyResult<ArrayView<T, IxDyn>> {
+        let shape = IxDyn(&self.shape());
+        let strides = IxDyn(&self.element_strides()?);
+        let obj: &PyArrayObject = self.as_ref();
+        let view = unsafe {
+            ArrayView::from_shape_ptr(shape.strides(strides), obj.data as *const T)
+        };
+        Ok(view)
+    }
+
+    pub fn copy_to_vec(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+
+    /// Register an exclusive borrow of this array's buffer with [`BORROWS`], returning the id to
+    /// pass back to [`release_borrow`] once done. Unlike [`PyReadwriteArray`], the borrow is not
+    /// tied to a `'py` lifetime, so it can be handed off across a thread boundary (e.g. a
+    /// background job) and released from wherever the work actually finishes.
+    pub(crate) fn acquire_write_borrow(&self) -> PyResult<u64> {
+        self.is_contiguous()?;
+        self.is_writeable()?;
+        let obj: &PyArrayObject = self.as_ref();
+        let len = self.size() * std::mem::size_of::<T>();
+        acquire_borrow(obj.data as *const c_void, len, true)
+    }
+
     pub fn empty<'py>(py: Python<'py>, shape: &[usize]) -> PyResult<&'py Self> {
         let api = api(py);
         let empty = unsafe { *api.empty };
@@ -474,6 +521,22 @@ where
         Ok(value)
     }
 
+    pub fn iter(&self) -> PyArrayIter<T> {
+        match unsafe { self.slice() } {
+            Ok(slice) => PyArrayIter::Contiguous(slice.iter()),
+            Err(_) => PyArrayIter::Strided { array: self, index: 0, size: self.size() },
+        }
+    }
+
+    pub unsafe fn iter_mut(&self) -> PyResult<PyArrayIterMut<T>> {
+        self.is_writeable()?;
+        let iter = match self.slice_mut() {
+            Ok(slice) => PyArrayIterMut::Contiguous(slice.iter_mut()),
+            Err(_) => PyArrayIterMut::Strided { array: self, index: 0, size: self.size() },
+        };
+        Ok(iter)
+    }
+
     pub fn set(&self, index: usize, value: T) -> PyResult<()> {
         self.is_writeable()?;
         let data = self.data(index)?;
@@ -501,6 +564,88 @@ where
         Ok(slice)
     }
 
+    // Slice this array axis-by-axis, the way `numpy.ndarray.__getitem__` resolves a tuple of
+    // `slice` objects, returning a new `PyArray<T>` that shares the original's data buffer. Axes
+    // not covered by `axes` are kept whole. Resolution of each `start:stop:step` (negative
+    // indices, clamping, direction) is delegated to `PySlice::indices`.
+    pub fn slice_axis<'py>(&'py self, axes: &[&PySlice]) -> PyResult<&'py Self> {
+        let py = self.py();
+        let shape = self.shape();
+        let obj: &PyArrayObject = self.as_ref();
+        let nd = obj.nd as usize;
+        if axes.len() > nd {
+            return Err(PyIndexError::new_err(format!(
+                "too many indices for array (expected at most {}, found {})",
+                nd,
+                axes.len(),
+            )))
+        }
+        let byte_strides = self.strides_slice();
+        let item_size = std::mem::size_of::<T>() as npy_intp;
+
+        let mut data_offset: isize = 0;
+        let mut new_dims = Vec::<npy_intp>::with_capacity(nd);
+        let mut new_strides = Vec::<npy_intp>::with_capacity(nd);
+        for axis in 0..nd {
+            let parent_stride = byte_strides[axis];
+            match axes.get(axis) {
+                None => {
+                    new_dims.push(shape[axis] as npy_intp);
+                    new_strides.push(parent_stride);
+                },
+                Some(slice) => {
+                    let indices = slice.indices(shape[axis] as ffi::Py_ssize_t)?;
+                    data_offset += (indices.start as isize) * (parent_stride as isize);
+                    new_dims.push(indices.slicelength as npy_intp);
+                    new_strides.push(indices.step as npy_intp * parent_stride);
+                },
+            }
+        }
+
+        // A view is C-contiguous only if it happens to retrace the standard row-major layout
+        // of its own (sliced) shape.
+        let mut expected_stride = item_size;
+        let mut row_major = true;
+        for i in (0..nd).rev() {
+            if new_dims[i] > 1 && new_strides[i] != expected_stride {
+                row_major = false;
+            }
+            expected_stride *= new_dims[i].max(1);
+        }
+
+        let mut flags = obj.flags & PyArrayFlags::WRITEABLE;
+        if row_major {
+            flags |= PyArrayFlags::C_CONTIGUOUS;
+        }
+
+        let api = api(py);
+        let new_from_descriptor = unsafe { *api.new_from_descriptor };
+        let dtype = T::dtype(py)?;
+        let data = unsafe { obj.data.offset(data_offset) as *mut c_void };
+        let array = new_from_descriptor(
+            api.type_ndarray.as_ptr(),
+            dtype.as_ptr(),
+            nd as c_int,
+            new_dims.as_ptr(),
+            new_strides.as_ptr(),
+            data,
+            flags,
+            std::ptr::null_mut(),
+        );
+        if PyErr::occurred(py) {
+            match PyErr::take(py) {
+                None => unreachable!(),
+                Some(err) => return Err(err),
+            }
+        }
+        let set_base_object = unsafe { *api.set_base_object };
+        let base = self.as_ptr();
+        set_base_object(array, base);
+        unsafe { pyo3::ffi::Py_INCREF(base); }
+        let array = unsafe { &*(array as *const Self) };
+        Ok(array)
+    }
+
     pub fn zeros<'py>(py: Python<'py>, shape: &[usize]) -> PyResult<&'py Self> {
         let api = api(py);
         let zeros = unsafe { *api.zeros };
@@ -525,6 +670,26 @@ where
 
 // Private interface.
 impl<T> PyArray<T> {
+    // Convert `PyArrayObject`'s byte strides to element strides, as `ndarray` expects.
+    fn element_strides(&self) -> PyResult<Vec<usize>> {
+        let obj: &PyArrayObject = self.as_ref();
+        let nd = obj.nd as usize;
+        let strides = unsafe { std::slice::from_raw_parts(obj.strides, nd) };
+        let item_size = std::mem::size_of::<T>() as npy_intp;
+        let mut result = Vec::<usize>::with_capacity(nd);
+        for stride in strides {
+            if *stride < 0 || item_size == 0 || stride % item_size != 0 {
+                return Err(PyValueError::new_err(format!(
+                    "bad byte stride (expected a non-negative multiple of {}, found {})",
+                    item_size,
+                    stride,
+                )))
+            }
+            result.push((stride / item_size) as usize);
+        }
+        Ok(result)
+    }
+
     fn is_contiguous(&self) -> PyResult<()> {
         let obj: &PyArrayObject = self.as_ref();
         if obj.flags & PyArrayFlags::C_CONTIGUOUS == 0 {
@@ -626,6 +791,265 @@ where
 }
 
 
+// ===============================================================================================
+//
+// Strided iteration.
+//
+// ===============================================================================================
+
+pub enum PyArrayIter<'a, T> {
+    Contiguous(std::slice::Iter<'a, T>),
+    Strided { array: &'a PyArray<T>, index: usize, size: usize },
+}
+
+impl<'a, T> Iterator for PyArrayIter<'a, T>
+where
+    T: Copy + Dtype,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self {
+            Self::Contiguous(iter) => iter.next(),
+            Self::Strided { array, index, size } => {
+                if *index >= *size {
+                    None
+                } else {
+                    let data = array.data(*index).ok()?;
+                    *index += 1;
+                    Some(unsafe { &*(data as *const T) })
+                }
+            },
+        }
+    }
+}
+
+pub enum PyArrayIterMut<'a, T> {
+    Contiguous(std::slice::IterMut<'a, T>),
+    Strided { array: &'a PyArray<T>, index: usize, size: usize },
+}
+
+impl<'a, T> Iterator for PyArrayIterMut<'a, T>
+where
+    T: Copy + Dtype,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        match self {
+            Self::Contiguous(iter) => iter.next(),
+            Self::Strided { array, index, size } => {
+                if *index >= *size {
+                    None
+                } else {
+                    let data = array.data(*index).ok()?;
+                    *index += 1;
+                    Some(unsafe { &mut *(data as *mut T) })
+                }
+            },
+        }
+    }
+}
+
+
+// ===============================================================================================
+//
+// Safe borrow guards.
+//
+// A process-wide registry of the buffers currently borrowed from Python, keyed by their base
+// data pointer and byte length, is used to reject overlapping mutable borrows (and mutable
+// borrows overlapping a readonly one), mirroring the aliasing rules of `&[T]` / `&mut [T]`.
+//
+// ===============================================================================================
+
+struct BorrowEntry {
+    id: u64,
+    ptr: usize,
+    len: usize,
+    write: bool,
+}
+
+struct BorrowRegistry {
+    entries: Vec<BorrowEntry>,
+    next_id: u64,
+}
+
+static BORROWS: Mutex<BorrowRegistry> = Mutex::new(BorrowRegistry {
+    entries: Vec::new(),
+    next_id: 0,
+});
+
+pub(crate) fn acquire_borrow(ptr: *const c_void, len: usize, write: bool) -> PyResult<u64> {
+    let ptr = ptr as usize;
+    let mut registry = BORROWS.lock().unwrap();
+    for entry in registry.entries.iter() {
+        let overlaps = ptr < entry.ptr + entry.len && entry.ptr < ptr + len;
+        if overlaps && (write || entry.write) {
+            return Err(PyValueError::new_err(
+                "array is already borrowed (conflicting readwrite access)"
+            ))
+        }
+    }
+    let id = registry.next_id;
+    registry.next_id += 1;
+    registry.entries.push(BorrowEntry { id, ptr, len, write });
+    Ok(id)
+}
+
+pub(crate) fn release_borrow(id: u64) {
+    let mut registry = BORROWS.lock().unwrap();
+    registry.entries.retain(|entry| entry.id != id);
+}
+
+/// A readonly borrow of a `PyArray<T>`, checked against concurrent mutable borrows of the
+/// same (or overlapping) buffer.
+pub struct PyReadonlyArray<'py, T> {
+    array: &'py PyArray<T>,
+    id: u64,
+}
+
+impl<'py, T> PyReadonlyArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    fn new(array: &'py PyArray<T>) -> PyResult<Self> {
+        array.is_contiguous()?;
+        let obj: &PyArrayObject = array.as_ref();
+        let len = array.size() * std::mem::size_of::<T>();
+        let id = acquire_borrow(obj.data as *const c_void, len, false)?;
+        Ok(Self { array, id })
+    }
+
+    /// The underlying `PyArray`, for callers needing its full (non-slice) interface, e.g.
+    /// `get`/`shape`/`size`.
+    pub(crate) fn array(&self) -> &'py PyArray<T> {
+        self.array
+    }
+}
+
+impl<'py, T> Deref for PyReadonlyArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { self.array.slice() }.expect("contiguity was checked at construction")
+    }
+}
+
+impl<'py, T> Drop for PyReadonlyArray<'py, T> {
+    fn drop(&mut self) {
+        release_borrow(self.id);
+    }
+}
+
+impl<'py, T> FromPyObject<'py> for PyReadonlyArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    fn extract(obj: &'py PyAny) -> PyResult<Self> {
+        let array: &'py PyArray<T> = FromPyObject::extract(obj)?;
+        Self::new(array)
+    }
+}
+
+/// A readwrite borrow of a `PyArray<T>`, exclusive of any other live borrow of the same (or
+/// overlapping) buffer.
+pub struct PyReadwriteArray<'py, T> {
+    array: &'py PyArray<T>,
+    id: u64,
+}
+
+impl<'py, T> PyReadwriteArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    fn new(array: &'py PyArray<T>) -> PyResult<Self> {
+        let id = array.acquire_write_borrow()?;
+        Ok(Self { array, id })
+    }
+
+    /// The underlying `PyArray`, for callers needing its full (non-slice) interface, e.g.
+    /// `get`/`set`/`shape`/`size`.
+    pub(crate) fn array(&self) -> &'py PyArray<T> {
+        self.array
+    }
+}
+
+impl<'py, T> Deref for PyReadwriteArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { self.array.slice() }.expect("contiguity was checked at construction")
+    }
+}
+
+impl<'py, T> DerefMut for PyReadwriteArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { self.array.slice_mut() }.expect("contiguity/writeability were checked at construction")
+    }
+}
+
+impl<'py, T> Drop for PyReadwriteArray<'py, T> {
+    fn drop(&mut self) {
+        release_borrow(self.id);
+    }
+}
+
+impl<'py, T> FromPyObject<'py> for PyReadwriteArray<'py, T>
+where
+    T: Copy + Dtype,
+{
+    fn extract(obj: &'py PyAny) -> PyResult<Self> {
+        let array: &'py PyArray<T> = FromPyObject::extract(obj)?;
+        Self::new(array)
+    }
+}
+
+
+// ===============================================================================================
+//
+// Disjoint parallel access.
+//
+// `PyArray<T>` is not `Sync`: sharing it across threads in general would legalize two workers
+// aliasing the same element. `PyTransportEngine::transport_with`'s parallel path is the one
+// place that needs to, and only ever hands each rayon task a distinct index into the same
+// array, so this narrow wrapper — not `PyArray<T>` itself — is what is made `Sync`, scoping the
+// "threads only touch their own index" assumption to the call sites that actually rely on it.
+//
+// ===============================================================================================
+
+pub struct DisjointChunks<'a, T>(&'a PyArray<T>);
+
+unsafe impl<'a, T> Sync for DisjointChunks<'a, T> where T: Send {}
+
+impl<'a, T> DisjointChunks<'a, T>
+where
+    T: Copy + Dtype,
+{
+    /// Wrap `array` for sharing across threads. Callers are responsible for ensuring that, for
+    /// the lifetime of this wrapper, distinct threads only ever `get`/`set` distinct indices.
+    pub fn new(array: &'a PyArray<T>) -> Self {
+        Self(array)
+    }
+
+    pub fn get(&self, index: usize) -> PyResult<T> {
+        self.0.get(index)
+    }
+
+    pub fn set(&self, index: usize, value: T) -> PyResult<()> {
+        self.0.set(index, value)
+    }
+}
+
+
 // ===============================================================================================
 //
 // D-types.
@@ -664,6 +1088,13 @@ impl Dtype for CState {
     }
 }
 
+impl Dtype for CSegment {
+    #[inline]
+    fn dtype(py: Python) -> PyResult<PyObject> {
+        Ok(api(py).dtype_segment.clone_ref(py))
+    }
+}
+
 impl Dtype for usize {
     #[inline]
     fn dtype(py: Python) -> PyResult<PyObject> {
@@ -785,6 +1216,80 @@ impl<T> ToPyObject for PyScalar<T> {
 }
 
 
+// ===============================================================================================
+//
+// Numpy-style broadcasting of several input shapes.
+//
+// ===============================================================================================
+
+pub struct Broadcast {
+    shape: Vec<usize>,
+    strides: Vec<Vec<usize>>,
+}
+
+impl Broadcast {
+    pub fn new(shapes: &[&[usize]]) -> PyResult<Self> {
+        let ndim = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+        let mut shape = vec![1_usize; ndim];
+        for input in shapes {
+            let offset = ndim - input.len();
+            for (i, &size) in input.iter().enumerate() {
+                let axis = offset + i;
+                if size != 1 && shape[axis] != 1 && size != shape[axis] {
+                    return Err(PyValueError::new_err(format!(
+                        "could not broadcast shapes (incompatible size {} on axis {})",
+                        size,
+                        axis,
+                    )))
+                }
+                shape[axis] = shape[axis].max(size);
+            }
+        }
+
+        let strides: Vec<Vec<usize>> = shapes
+            .iter()
+            .map(|input| {
+                let offset = ndim - input.len();
+                let mut strides = vec![0_usize; ndim];
+                let mut stride = 1_usize;
+                for (i, &size) in input.iter().enumerate().rev() {
+                    let axis = offset + i;
+                    strides[axis] = if size == 1 { 0 } else { stride };
+                    stride *= size;
+                }
+                strides
+            })
+            .collect();
+
+        Ok(Self { shape, strides })
+    }
+
+    #[inline]
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    // Map a flat index over the broadcast output shape back to the flat index of the `input`-th
+    // input array (stretched axes, i.e. those of size one, always map to index zero).
+    pub fn map(&self, input: usize, index: usize) -> usize {
+        let strides = &self.strides[input];
+        let mut remainder = index;
+        let mut result = 0_usize;
+        for i in (0..self.shape.len()).rev() {
+            let size = self.shape[i];
+            let j = remainder % size;
+            remainder /= size;
+            result += j * strides[i];
+        }
+        result
+    }
+}
+
+
 // ===============================================================================================
 //
 // Arguments conversion.
@@ -805,6 +1310,15 @@ impl<'a> ArrayOrFloat<'a> {
         }
     }
 
+    // Get the value at `index`, a flat index over the `broadcast` output shape, mapping it back
+    // to this input's own flat index (scalars and stretched axes always resolve to index zero).
+    pub fn get_broadcast(&self, broadcast: &Broadcast, input: usize, index: usize) -> PyResult<Float> {
+        match self {
+            Self::Array(a) => a.get(broadcast.map(input, index)),
+            Self::Float(s) => Ok(*s),
+        }
+    }
+
     pub fn is_float(&self) -> bool {
         match self {
             Self::Array(_) => false,
@@ -812,6 +1326,13 @@ impl<'a> ArrayOrFloat<'a> {
         }
     }
 
+    pub fn shape(&self) -> Vec<usize> {
+        match self {
+            Self::Array(a) => a.shape(),
+            Self::Float(_) => vec![],
+        }
+    }
+
     pub fn size(&self) -> usize {
         match self {
             Self::Array(a) => a.size(),
@@ -820,12 +1341,6 @@ impl<'a> ArrayOrFloat<'a> {
     }
 }
 
-#[derive(pyo3::FromPyObject)]
-pub enum ArrayOrFloat3<'a> {
-    Array(&'a PyArray<Float>),
-    Float3(Float3),
-}
-
 impl IntoPy<PyObject> for Float3 {
     fn into_py(self, py: Python) -> PyObject {
         let result = PyArray::<Float>::empty(py, &[3]).unwrap();
@@ -0,0 +1,179 @@
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use std::time::Instant;
+
+#[cfg(feature = "stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "stats")]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+
+// ===============================================================================================
+// Instrumented global allocator, enabled by the `stats` feature.
+//
+// Four monotonic (or quasi-monotonic) counters are maintained: the number of live resident bytes,
+// their observed peak since the last reset, the cumulative number of bytes ever allocated and the
+// cumulative number of allocations. `Profile::__enter__`/`__exit__` snapshot these around a run to
+// report the increment due to that run alone.
+// ===============================================================================================
+
+#[cfg(feature = "stats")]
+struct StatsAllocator;
+
+#[cfg(feature = "stats")]
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "stats")]
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "stats")]
+static TOTAL_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "stats")]
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// Guards against overlapping `Profile` blocks, which would otherwise race over the single
+// process-wide `PEAK_BYTES` high-water mark (the inner block's reset on `__enter__` would wipe
+// out the outer block's reference point).
+#[cfg(feature = "stats")]
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "stats")]
+unsafe impl GlobalAlloc for StatsAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT_BYTES.fetch_add(layout.size(), Ordering::Relaxed)
+                + layout.size();
+            PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+            TOTAL_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "stats")]
+#[global_allocator]
+static ALLOCATOR: StatsAllocator = StatsAllocator;
+
+// Snapshot of (current resident bytes, peak resident bytes, total bytes ever allocated, total
+// allocation count).
+#[cfg(feature = "stats")]
+fn snapshot() -> (usize, usize, usize, usize) {
+    (
+        CURRENT_BYTES.load(Ordering::Relaxed),
+        PEAK_BYTES.load(Ordering::Relaxed),
+        TOTAL_BYTES.load(Ordering::Relaxed),
+        NUM_ALLOCATIONS.load(Ordering::Relaxed),
+    )
+}
+
+// Restarts the peak-tracking high-water mark from the current resident byte count.
+#[cfg(feature = "stats")]
+fn reset_peak(current: usize) {
+    PEAK_BYTES.store(current, Ordering::Relaxed);
+}
+
+
+// ===============================================================================================
+// Python context manager reporting allocation and timing statistics over a `with` block.
+//
+// When the `stats` feature is disabled, allocation counters are always zero and only the wall
+// time is measured, so that release builds pay no instrumentation cost.
+//
+// Only one `Profile` block may be active at a time: `PEAK_BYTES` is a single process-wide
+// high-water mark, so a nested or concurrent block would reset the mark the outer block is
+// still relying on. `__enter__` raises if a block is already active rather than silently
+// producing a wrong `peak_bytes` for the outer one.
+// ===============================================================================================
+
+#[pyclass(name = "Profile", module = "goupil")]
+pub(crate) struct PyProfile {
+    #[pyo3(get)]
+    bytes_allocated: usize,
+    #[pyo3(get)]
+    peak_bytes: usize,
+    #[pyo3(get)]
+    num_allocations: usize,
+    #[pyo3(get)]
+    wall_time: f64,
+
+    start: Option<Instant>,
+    #[cfg(feature = "stats")]
+    start_bytes: usize,
+    #[cfg(feature = "stats")]
+    start_total: usize,
+    #[cfg(feature = "stats")]
+    start_count: usize,
+}
+
+impl PyProfile {
+    fn new() -> Self {
+        Self {
+            bytes_allocated: 0,
+            peak_bytes: 0,
+            num_allocations: 0,
+            wall_time: 0.0,
+            start: None,
+            #[cfg(feature = "stats")]
+            start_bytes: 0,
+            #[cfg(feature = "stats")]
+            start_total: 0,
+            #[cfg(feature = "stats")]
+            start_count: 0,
+        }
+    }
+}
+
+#[pymethods]
+impl PyProfile {
+    fn __enter__(&mut self) -> PyResult<()> {
+        #[cfg(feature = "stats")]
+        {
+            if ACTIVE.swap(true, Ordering::AcqRel) {
+                return Err(PyRuntimeError::new_err(
+                    "Profile blocks cannot be nested or used concurrently"
+                ));
+            }
+            let (current, _, total, count) = snapshot();
+            self.start_bytes = current;
+            self.start_total = total;
+            self.start_count = count;
+            reset_peak(current);
+        }
+        self.start = Some(Instant::now());
+        Ok(())
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> bool {
+        if let Some(start) = self.start.take() {
+            self.wall_time = start.elapsed().as_secs_f64();
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            let (_, peak, total, count) = snapshot();
+            self.bytes_allocated = total.saturating_sub(self.start_total);
+            self.peak_bytes = peak.saturating_sub(self.start_bytes);
+            self.num_allocations = count.saturating_sub(self.start_count);
+            ACTIVE.store(false, Ordering::Release);
+        }
+
+        false
+    }
+}
+
+/// Return a context manager measuring wall time, and (when built with the `stats` feature)
+/// allocation counts and peak resident memory, over the enclosed block.
+#[pyfunction]
+pub fn profile() -> PyProfile {
+    PyProfile::new()
+}
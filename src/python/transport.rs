@@ -8,23 +8,31 @@ use crate::physics::process::{
 };
 use crate::transport::{
     agent::{TransportAgent, TransportBoundary, TransportStatus},
-    geometry::{ExternalTracer, GeometryDefinition, GeometryTracer, SimpleTracer, StratifiedTracer},
+    geometry::{CompositeTracer, ExternalTracer, GeometryDefinition, GeometryTracer, SimpleTracer,
+               StratifiedTracer},
     PhotonState,
     TransportMode::{self, Backward, Forward},
     TransportSettings,
 };
 use pyo3::{
     prelude::*,
-    types::{PyBytes, PyDict, PyString},
+    types::{PyBytes, PyDict, PyString, PyTuple},
 };
+use rayon::prelude::*;
 use rmp_serde::{Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
 use super::{
     ctrlc_catched,
     geometry::{PyExternalGeometry, PyGeometryDefinition},
     macros::{type_error, value_error},
     materials::PyMaterialRegistry,
-    numpy::{ArrayOrFloat, PyArray, PyScalar, ShapeArg},
+    numpy::{
+        release_borrow, ArrayOrFloat, DisjointChunks, PyArray, PyReadonlyArray, PyReadwriteArray,
+        PyScalar, ShapeArg,
+    },
     rand::PyRandomStream,
     prefix,
 };
@@ -420,6 +428,9 @@ impl PyTransportEngine {
             // Add current geometry materials to the registry.
             if let Some(geometry) = &self.geometry {
                 match geometry {
+                    PyGeometryDefinition::Composite(composite) => {
+                        self.update_with(&composite.borrow(py).inner, registry)?
+                    },
                     PyGeometryDefinition::External(external) => {
                         self.update_with(&external.borrow(py).inner, registry)?
                     },
@@ -495,9 +506,16 @@ impl PyTransportEngine {
 
     fn transport(
         &mut self,
-        states: &PyArray<CState>,
+        states: PyReadwriteArray<CState>,
         sources_energies: Option<ArrayOrFloat>,
+        threads: Option<usize>,
+        progress: Option<PyObject>,
+        tally: Option<bool>,
     ) -> Result<PyObject> {
+        // Hold an exclusive borrow on `states` for the whole call, so that a caller touching it
+        // from another thread while a run is in flight gets a clear error instead of racing.
+        let states = states.array();
+
         // Check constraints and states consistency.
         if let Some(constraints) = sources_energies.as_ref() {
             if let ArrayOrFloat::Array(constraints) = constraints {
@@ -525,24 +543,262 @@ impl PyTransportEngine {
                  found 'none')"
             ),
             Some(geometry) => match geometry {
+                PyGeometryDefinition::Composite(composite) => {
+                    self.transport_with::<_, CompositeTracer>(
+                        &composite.borrow(py).inner, states, sources_energies, threads, progress,
+                        tally,
+                    )
+                },
                 PyGeometryDefinition::External(external) => {
                     self.transport_with::<_, ExternalTracer>(
-                        &external.borrow(py).inner, states, sources_energies,
+                        &external.borrow(py).inner, states, sources_energies, threads, progress,
+                        tally,
                     )
                 },
                 PyGeometryDefinition::Simple(simple) => {
                     self.transport_with::<_, SimpleTracer>(
-                        &simple.borrow(py).0, states, sources_energies,
+                        &simple.borrow(py).0, states, sources_energies, threads, progress, tally,
                     )
                 },
                 PyGeometryDefinition::Stratified(stratified) => {
                     self.transport_with::<_, StratifiedTracer>(
-                        &stratified.borrow(py).inner, states, sources_energies,
+                        &stratified.borrow(py).inner, states, sources_energies, threads, progress,
+                        tally,
                     )
                 },
             },
         }
     }
+
+    /// Submit a transport run to a background thread and return immediately with a `TransportJob`
+    /// handle, instead of blocking until completion. `states` is held under an exclusive borrow
+    /// for the lifetime of the background job, not just for this call, so touching it from
+    /// Python while the job is in flight raises rather than racing the worker thread.
+    fn transport_async(
+        &mut self,
+        py: Python,
+        states: &PyArray<CState>,
+        sources_energies: Option<ArrayOrFloat>,
+    ) -> Result<PyTransportJob> {
+        // Check constraints and states consistency.
+        if let Some(constraints) = sources_energies.as_ref() {
+            if let ArrayOrFloat::Array(constraints) = constraints {
+                if constraints.size() != states.size() {
+                    value_error!(
+                        "bad constraints (expected a scalar or a size {} array, \
+                         found a size {} array)",
+                        states.size(),
+                        constraints.size(),
+                    )
+                }
+            }
+        }
+
+        // Compile, if not already done.
+        if !self.compiled {
+            self.compile(py, Some("Both"), None, None)?;
+        }
+
+        if self.geometry.is_none() {
+            type_error!(
+                "bad geometry (expected an instance of 'ExternalGeometry' or 'SimpleGeometry' \
+                 found 'none')"
+            )
+        }
+
+        // Clone the engine's state. Each job gets its own settings and its own RNG sub-stream,
+        // deterministically derived from the engine's base seed and a monotonic job nonce, so
+        // that concurrent jobs on a single engine never share mutable state.
+        let geometry = self.geometry.clone();
+        let registry: Py<PyMaterialRegistry> = self.registry.clone();
+        let settings: TransportSettings = self.settings.borrow(py).inner.clone();
+        let base_seed = self.random.borrow(py).seed();
+        let nonce = NEXT_JOB_SEED.fetch_add(1, Ordering::Relaxed);
+        let job_seed = split_mix64(base_seed ^ nonce);
+
+        // Hold an exclusive borrow on `states` from here until the background job finishes
+        // running (not merely until this call returns), so that a caller mutating `states` while
+        // a job is in flight gets a clear error instead of racing the worker thread.
+        let borrow_id = states.acquire_write_borrow()?;
+
+        let states_any: &PyAny = states;
+        let states_obj: Py<PyAny> = states_any.into();
+        let constraints_obj = sources_energies.as_ref().map(OwnedArrayOrFloat::from_ref);
+
+        let status = PyArray::<i32>::empty(py, &states.shape())?;
+        let status_any: &PyAny = status;
+        let status_obj: Py<PyAny> = status_any.into();
+
+        let state = Arc::new(JobState {
+            total: states.size(),
+            completed: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+            done: AtomicBool::new(false),
+        });
+        let job_state = state.clone();
+
+        let handle = thread::spawn(move || -> Result<()> {
+            let result = Python::with_gil(|py| -> Result<()> {
+                let geometry = geometry.expect("checked above");
+                let registry = registry.borrow(py);
+                let registry = &registry.inner;
+                let mut settings = settings;
+                let mut rng = PyRandomStream::new(Some(job_seed))?;
+                let states: &PyArray<CState> = FromPyObject::extract(states_obj.as_ref(py))?;
+                let status: &PyArray<i32> = FromPyObject::extract(status_obj.as_ref(py))?;
+                let constraints = constraints_obj
+                    .as_ref()
+                    .map(|c| c.as_array_or_float(py))
+                    .transpose()?;
+                if constraints.is_none() {
+                    settings.constraint = None;
+                }
+
+                match &geometry {
+                    PyGeometryDefinition::Composite(composite) => {
+                        let composite = composite.borrow(py);
+                        py.allow_threads(|| run_job::<_, CompositeTracer>(
+                            &composite.inner, registry, settings, &mut rng, states, status,
+                            constraints, &job_state,
+                        ))
+                    },
+                    PyGeometryDefinition::External(external) => {
+                        let external = external.borrow(py);
+                        py.allow_threads(|| run_job::<_, ExternalTracer>(
+                            &external.inner, registry, settings, &mut rng, states, status,
+                            constraints, &job_state,
+                        ))
+                    },
+                    PyGeometryDefinition::Simple(simple) => {
+                        let simple = simple.borrow(py);
+                        py.allow_threads(|| run_job::<_, SimpleTracer>(
+                            &simple.0, registry, settings, &mut rng, states, status,
+                            constraints, &job_state,
+                        ))
+                    },
+                    PyGeometryDefinition::Stratified(stratified) => {
+                        let stratified = stratified.borrow(py);
+                        py.allow_threads(|| run_job::<_, StratifiedTracer>(
+                            &stratified.inner, registry, settings, &mut rng, states, status,
+                            constraints, &job_state,
+                        ))
+                    },
+                }
+            });
+            // Release the borrow taken out in the submitting thread only once the run (or its
+            // setup) is actually done, whatever the outcome.
+            release_borrow(borrow_id);
+            // Also covers a setup error raised before `run_job` got to run.
+            job_state.done.store(true, Ordering::Relaxed);
+            result
+        });
+
+        Ok(PyTransportJob {
+            handle: Mutex::new(Some(handle)),
+            state,
+            status: status_obj,
+        })
+    }
+
+    /// Return a non-recursive, checkpointable iterator over a batch of photons, yielding
+    /// `(state, status)` for each one as it completes.
+    ///
+    /// Each photon is a work-item on an explicit `Vec`-backed stack (see `TransportIterator`)
+    /// instead of a call-stack frame: `next()` pops one item, runs it to a terminal
+    /// `TransportStatus` and yields it. The stack can be saved with `TransportIterator.checkpoint`
+    /// and continued later with `resume_iter`, so a run can be paused and resumed across process
+    /// restarts.
+    fn transport_iter(
+        &mut self,
+        py: Python,
+        states: PyReadonlyArray<CState>,
+        sources_energies: Option<ArrayOrFloat>,
+    ) -> Result<PyTransportIterator> {
+        // Only held long enough to copy the batch onto the stack below, guarding against a
+        // concurrent writer mutating `states` mid-copy.
+        let states = states.array();
+
+        if let Some(constraints) = sources_energies.as_ref() {
+            if let ArrayOrFloat::Array(constraints) = constraints {
+                if constraints.size() != states.size() {
+                    value_error!(
+                        "bad constraints (expected a scalar or a size {} array, \
+                         found a size {} array)",
+                        states.size(),
+                        constraints.size(),
+                    )
+                }
+            }
+        }
+
+        if !self.compiled {
+            self.compile(py, Some("Both"), None, None)?;
+        }
+
+        if self.geometry.is_none() {
+            type_error!(
+                "bad geometry (expected an instance of 'ExternalGeometry' or 'SimpleGeometry' \
+                 found 'none')"
+            )
+        }
+
+        // Check consistency of settings with explicit constraints.
+        if sources_energies.is_some() {
+            let settings = &self.settings.borrow(py).inner;
+            if settings.mode == TransportMode::Forward {
+                value_error!("bad constraints (unused in 'Forward' mode)")
+            } else if settings.constraint.is_none() {
+                value_error!("bad constraints (disabled by transport settings)")
+            }
+        }
+
+        // Push the batch onto the stack in reverse, so that `next()` yields photons in their
+        // original order.
+        let n = states.size();
+        let mut stack = Vec::with_capacity(n);
+        for i in (0..n).rev() {
+            let constraint = match sources_energies.as_ref() {
+                None => None,
+                Some(ArrayOrFloat::Array(constraints)) => Some(constraints.get(i)?),
+                Some(ArrayOrFloat::Float(constraint)) => Some(*constraint),
+            };
+            stack.push(WorkItem { state: states.get(i)?, constraint });
+        }
+
+        Ok(PyTransportIterator {
+            geometry: self.geometry.clone().expect("checked above"),
+            registry: self.registry.clone(),
+            settings: self.settings.clone(),
+            random: self.random.clone(),
+            stack,
+        })
+    }
+
+    /// Rebuild a `TransportIterator` from a checkpoint previously returned by
+    /// `TransportIterator.checkpoint`, resuming the outstanding work stack.
+    fn resume_iter(&mut self, py: Python, checkpoint: &PyBytes) -> Result<PyTransportIterator> {
+        if !self.compiled {
+            self.compile(py, Some("Both"), None, None)?;
+        }
+
+        if self.geometry.is_none() {
+            type_error!(
+                "bad geometry (expected an instance of 'ExternalGeometry' or 'SimpleGeometry' \
+                 found 'none')"
+            )
+        }
+
+        let mut deserializer = Deserializer::new(checkpoint.as_bytes());
+        let stack: Vec<WorkItem> = Deserialize::deserialize(&mut deserializer)?;
+
+        Ok(PyTransportIterator {
+            geometry: self.geometry.clone().expect("checked above"),
+            registry: self.registry.clone(),
+            settings: self.settings.clone(),
+            random: self.random.clone(),
+            stack,
+        })
+    }
 }
 
 impl PyTransportEngine {
@@ -561,9 +817,12 @@ impl PyTransportEngine {
         geometry: &'a G,
         states: &PyArray<CState>,
         constraints: Option<ArrayOrFloat>,
+        threads: Option<usize>,
+        progress: Option<PyObject>,
+        tally: Option<bool>,
     ) -> Result<PyObject>
     where
-        G: GeometryDefinition,
+        G: GeometryDefinition + Sync,
         T: GeometryTracer<'a, G>,
     {
         // Create the status array.
@@ -590,13 +849,279 @@ impl PyTransportEngine {
 
         // XXX Use table energy limits if no explicit bound was specified (?)
 
-        // Get a transport agent.
-        let rng: &mut PyRandomStream = &mut self.random.borrow_mut(py);
-        let mut agent = TransportAgent::<G, _, T>::new(geometry, registry, rng)?;
+        let threads = threads.unwrap_or(1);
+        let n = states.size();
+        let completed = AtomicUsize::new(0);
+        let aborted = AtomicBool::new(false);
+        let tally = tally.unwrap_or(false).then(StatusTally::new);
+
+        if threads <= 1 {
+            // Get a transport agent.
+            let rng: &mut PyRandomStream = &mut self.random.borrow_mut(py);
+            let mut agent = TransportAgent::<G, _, T>::new(geometry, registry, rng)?;
+
+            // Do the Monte Carlo transport, iterating over `states` in place rather than
+            // round-tripping each photon through a `get`/`set` pair.
+            let states_iter = unsafe { states.iter_mut()? };
+            for (i, cstate) in states_iter.enumerate() {
+                if aborted.load(Ordering::Relaxed) {
+                    status.set(i, ABORTED_STATUS)?;
+                    if let Some(tally) = tally.as_ref() {
+                        tally.record(ABORTED_STATUS);
+                    }
+                    continue;
+                }
+
+                let mut state: PhotonState = (*cstate).into();
+                if let Some(constraints) = constraints.as_ref() {
+                    let constraint = match constraints {
+                        ArrayOrFloat::Array(constraints) => constraints.get(i)?,
+                        ArrayOrFloat::Float(constraint) => *constraint,
+                    };
+                    settings.constraint = Some(constraint);
+                }
+                let flag = agent.transport(&settings, &mut state)?;
+                let code: i32 = flag.into();
+                *cstate = state.into();
+                status.set(i, code)?;
+                if let Some(tally) = tally.as_ref() {
+                    tally.record(code);
+                }
+
+                let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                if done % PROGRESS_STRIDE == 0 || done == n {
+                    if !report_progress(&progress, done, n)? {
+                        aborted.store(true, Ordering::Relaxed);
+                    }
+                }
+
+                if i % 100 == 0 { // Check for a Ctrl+C interrupt, catched by Python.
+                    ctrlc_catched()?;
+                }
+            }
+        } else {
+            // Each photon is transported with its own sub-stream, deterministically derived from
+            // the engine's base seed and the photon's global index. This makes the outcome
+            // independent of the number of threads (two or more) and of how photons are chunked
+            // between them. The single-threaded path above draws from the engine's own stream
+            // instead, so its results differ from those of this path.
+            let base_seed = self.random.borrow(py).seed();
+
+            // `states`/`status` are shared read-write across workers, but each task only ever
+            // touches its own index `i`, so `DisjointChunks` (not `PyArray` itself) is what gets
+            // to be `Sync` here. Constraints, if an array, are copied out up front instead: they
+            // are only ever read, but copying avoids sharing a `PyArray` across threads at all.
+            let states = DisjointChunks::new(states);
+            let status = DisjointChunks::new(status);
+            let constraint_values: Option<Vec<Float>> = match constraints.as_ref() {
+                Some(ArrayOrFloat::Array(array)) => {
+                    let mut values = Vec::with_capacity(array.size());
+                    for i in 0..array.size() {
+                        values.push(array.get(i)?);
+                    }
+                    Some(values)
+                },
+                _ => None,
+            };
+            let constraint_scalar = match constraints.as_ref() {
+                Some(ArrayOrFloat::Float(value)) => Some(*value),
+                _ => None,
+            };
+
+            // Process the batch in GIL-releasing slices, so that `ctrlc_catched` keeps firing
+            // between slices from the driving thread.
+            let chunk = std::cmp::max(1, n / (4 * threads));
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(anyhow::Error::from)?;
+            let mut i0 = 0;
+            while i0 < n {
+                let i1 = std::cmp::min(i0 + chunk, n);
+                py.allow_threads(|| pool.install(|| {
+                    (i0..i1).into_par_iter().try_for_each(|i| -> Result<()> {
+                        if aborted.load(Ordering::Relaxed) {
+                            status.set(i, ABORTED_STATUS)?;
+                            if let Some(tally) = tally.as_ref() {
+                                tally.record(ABORTED_STATUS);
+                            }
+                            return Ok(());
+                        }
+
+                        let seed = split_mix64(base_seed ^ (i as u64));
+                        let mut rng = PyRandomStream::new(Some(seed))?;
+                        let mut agent = TransportAgent::<G, _, T>::new(geometry, registry, &mut rng)?;
+
+                        let mut state: PhotonState = states.get(i)?.into();
+                        let mut settings = settings.clone();
+                        if let Some(values) = constraint_values.as_ref() {
+                            settings.constraint = Some(values[i]);
+                        } else if let Some(value) = constraint_scalar {
+                            settings.constraint = Some(value);
+                        }
+                        let flag = agent.transport(&settings, &mut state)?;
+                        let code: i32 = flag.into();
+                        states.set(i, state.into())?;
+                        status.set(i, code)?;
+                        if let Some(tally) = tally.as_ref() {
+                            tally.record(code);
+                        }
+
+                        let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                        if done % PROGRESS_STRIDE == 0 || done == n {
+                            if !report_progress(&progress, done, n)? {
+                                aborted.store(true, Ordering::Relaxed);
+                            }
+                        }
+                        Ok(())
+                    })
+                }))?;
+                ctrlc_catched()?;
+                i0 = i1;
+            }
+        }
 
-        // Do the Monte Carlo transport.
+        let status: &PyAny = status;
+        match tally {
+            None => Ok(status.into()),
+            Some(tally) => {
+                let result = PyTuple::new(py, [status.into_py(py), tally.into_dict(py)?]);
+                Ok(result.into())
+            },
+        }
+    }
+}
+
+
+// ===============================================================================================
+// Background transport job, returned by `PyTransportEngine::transport_async`.
+// ===============================================================================================
+
+// Monotonic nonce mixed into a job's RNG seed, so that consecutive jobs never draw from the same
+// sub-stream even when requested with the same engine state.
+static NEXT_JOB_SEED: AtomicU64 = AtomicU64::new(0);
+
+struct JobState {
+    total: usize,
+    completed: AtomicUsize,
+    cancelled: AtomicBool,
+    done: AtomicBool,
+}
+
+#[pyclass(name = "TransportJob", module = "goupil")]
+pub(crate) struct PyTransportJob {
+    handle: Mutex<Option<JoinHandle<Result<()>>>>,
+    state: Arc<JobState>,
+    status: Py<PyAny>,
+}
+
+#[pymethods]
+impl PyTransportJob {
+    /// Cooperatively request that the job stop as soon as possible. Photons already in flight
+    /// run to completion; any remaining ones are marked with the `ABORTED` status.
+    fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Return `True` once the background thread has finished, whether it completed, was
+    /// cancelled, or hit a transport error (in which case `.result()`/`.join()` will raise it).
+    fn done(&self) -> bool {
+        self.state.done.load(Ordering::Relaxed)
+    }
+
+    /// Return the fraction, in `[0, 1]`, of photons transported so far.
+    fn progress(&self) -> f64 {
+        let total = self.state.total.max(1);
+        self.state.completed.load(Ordering::Relaxed) as f64 / total as f64
+    }
+
+    /// Block until the job has finished.
+    fn join(&self, py: Python) -> Result<()> {
+        self.wait(py)
+    }
+
+    /// Block until the job has finished and return its status array.
+    fn result(&self, py: Python) -> Result<PyObject> {
+        self.wait(py)?;
+        Ok(self.status.clone_ref(py))
+    }
+}
+
+impl PyTransportJob {
+    fn wait(&self, py: Python) -> Result<()> {
+        let handle = self.handle
+            .lock()
+            .unwrap()
+            .take();
+        if let Some(handle) = handle {
+            match py.allow_threads(|| handle.join()) {
+                Ok(result) => result?,
+                Err(_) => value_error!("bad job (background transport thread panicked)"),
+            }
+        }
+        Ok(())
+    }
+}
+
+// A cloneable, 'static stand-in for `ArrayOrFloat`, used to ship constraints across to a job's
+// background thread, where they are re-extracted under a freshly acquired GIL.
+enum OwnedArrayOrFloat {
+    Array(Py<PyAny>),
+    Float(Float),
+}
+
+impl OwnedArrayOrFloat {
+    fn from_ref(value: &ArrayOrFloat) -> Self {
+        match value {
+            ArrayOrFloat::Array(array) => {
+                let array: &PyAny = *array;
+                Self::Array(array.into())
+            },
+            ArrayOrFloat::Float(value) => Self::Float(*value),
+        }
+    }
+
+    fn as_array_or_float<'py>(&'py self, py: Python<'py>) -> Result<ArrayOrFloat<'py>> {
+        match self {
+            Self::Array(array) => {
+                let array = FromPyObject::extract(array.as_ref(py))?;
+                Ok(ArrayOrFloat::Array(array))
+            },
+            Self::Float(value) => Ok(ArrayOrFloat::Float(*value)),
+        }
+    }
+}
+
+// Run a single job's Monte Carlo loop to completion (or cancellation), reporting progress through
+// `job_state`. This is the background-thread counterpart of `PyTransportEngine::transport_with`'s
+// single-threaded path.
+fn run_job<'a, G, T>(
+    geometry: &'a G,
+    registry: &MaterialRegistry,
+    mut settings: TransportSettings,
+    rng: &mut PyRandomStream,
+    states: &PyArray<CState>,
+    status: &PyArray<i32>,
+    constraints: Option<ArrayOrFloat>,
+    job_state: &Arc<JobState>,
+) -> Result<()>
+where
+    G: GeometryDefinition,
+    T: GeometryTracer<'a, G>,
+{
+    // Run the loop in an inner closure so that `done` is set unconditionally below, whatever the
+    // outcome: a `?`-propagated error used to skip straight past the `done.store` at the end of
+    // this function, leaving `PyTransportJob::done()` stuck returning `false` forever on any
+    // transport error instead of letting `.result()`/`.join()` observe and raise it.
+    let result = (|| -> Result<()> {
+        let mut agent = TransportAgent::<G, _, T>::new(geometry, registry, rng)?;
         let n = states.size();
         for i in 0..n {
+            if job_state.cancelled.load(Ordering::Relaxed) {
+                status.set(i, ABORTED_STATUS)?;
+                continue;
+            }
+
             let mut state: PhotonState = states.get(i)?.into();
             if let Some(constraints) = constraints.as_ref() {
                 let constraint = match constraints {
@@ -608,14 +1133,148 @@ impl PyTransportEngine {
             let flag = agent.transport(&settings, &mut state)?;
             states.set(i, state.into())?;
             status.set(i, flag.into())?;
+            job_state.completed.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    })();
+    job_state.done.store(true, Ordering::Relaxed);
+    result
+}
 
-            if i % 100 == 0 { // Check for a Ctrl+C interrupt, catched by Python.
-                ctrlc_catched()?;
+// Cadence, in completed photons, at which the optional progress callback is invoked.
+const PROGRESS_STRIDE: usize = 1000;
+
+// Re-acquires the GIL (a no-op if already held) to call the optional progress callback with
+// `(completed, total)`. Returns `false` if the callback returned `False`, signalling that the
+// run should be aborted.
+fn report_progress(progress: &Option<PyObject>, completed: usize, total: usize) -> Result<bool> {
+    match progress {
+        None => Ok(true),
+        Some(callback) => Python::with_gil(|py| {
+            let result = callback.call1(py, (completed, total))?;
+            match result.extract::<bool>(py) {
+                Ok(false) => Ok(false),
+                _ => Ok(true),
             }
-        }
+        }),
+    }
+}
 
-        let status: &PyAny = status;
-        Ok(status.into())
+// A SplitMix64-style mixer, used to derive a reproducible, independent RNG seed for photon `i`
+// from the engine's base seed, regardless of thread count or chunking.
+fn split_mix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+
+// ===============================================================================================
+// Checkpointable photon iterator, returned by `PyTransportEngine::transport_iter` and
+// `PyTransportEngine::resume_iter`.
+//
+// Each pending photon is a `WorkItem` on a `Vec`-backed stack. `next()` pops one, runs it to a
+// terminal `TransportStatus` via the ordinary (recursive) transport agent, and returns it; nothing
+// is ever pushed back onto the stack. This gives callers a pausable, resumable driver over a batch
+// of photons — the stack can be serialized between calls and restored later — but it does not
+// replace the agent's internal recursion for scatter chains or pair-production secondaries, which
+// still runs, unobserved, underneath each `next()` call.
+// ===============================================================================================
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct WorkItem {
+    state: CState,
+    constraint: Option<Float>,
+}
+
+/// A checkpointable driver over a batch of photons.
+///
+/// Each call to `next()` pops one pending photon off an explicit stack, runs it to a terminal
+/// `TransportStatus` and returns it. The stack can be serialized with `checkpoint` and restored
+/// later via `TransportEngine.resume_iter`, so a batch can be driven incrementally and picked back
+/// up across process restarts. This drives one photon at a time to completion through the
+/// ordinary transport agent; it does not expose or checkpoint the scatter chains/pair-production
+/// secondaries the agent resolves internally for each one.
+#[pyclass(name = "TransportIterator", module = "goupil")]
+pub(crate) struct PyTransportIterator {
+    geometry: PyGeometryDefinition,
+    registry: Py<PyMaterialRegistry>,
+    settings: Py<PyTransportSettings>,
+    random: Py<PyRandomStream>,
+    stack: Vec<WorkItem>,
+}
+
+#[pymethods]
+impl PyTransportIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    /// Pop and run the next pending photon, returning its final `(state, status)`, or `None` once
+    /// the stack is empty.
+    fn __next__(&mut self, py: Python) -> Result<Option<(PyObject, i32)>> {
+        let item = match self.stack.pop() {
+            None => return Ok(None),
+            Some(item) => item,
+        };
+
+        let registry = self.registry.borrow(py);
+        let registry = &registry.inner;
+        let mut settings = self.settings.borrow(py).inner.clone();
+        settings.constraint = item.constraint;
+        let rng: &mut PyRandomStream = &mut self.random.borrow_mut(py);
+
+        let mut state: PhotonState = item.state.into();
+        let flag = match &self.geometry {
+            PyGeometryDefinition::Composite(composite) => {
+                let composite = composite.borrow(py);
+                let mut agent = TransportAgent::<_, _, CompositeTracer>::new(
+                    &composite.inner, registry, rng,
+                )?;
+                agent.transport(&settings, &mut state)?
+            },
+            PyGeometryDefinition::External(external) => {
+                let external = external.borrow(py);
+                let mut agent = TransportAgent::<_, _, ExternalTracer>::new(
+                    &external.inner, registry, rng,
+                )?;
+                agent.transport(&settings, &mut state)?
+            },
+            PyGeometryDefinition::Simple(simple) => {
+                let simple = simple.borrow(py);
+                let mut agent = TransportAgent::<_, _, SimpleTracer>::new(
+                    &simple.0, registry, rng,
+                )?;
+                agent.transport(&settings, &mut state)?
+            },
+            PyGeometryDefinition::Stratified(stratified) => {
+                let stratified = stratified.borrow(py);
+                let mut agent = TransportAgent::<_, _, StratifiedTracer>::new(
+                    &stratified.inner, registry, rng,
+                )?;
+                agent.transport(&settings, &mut state)?
+            },
+        };
+        let code: i32 = flag.into();
+
+        let final_state: CState = state.into();
+        let result = PyArray::<CState>::from_iter(py, &[1], [final_state].into_iter())?;
+        Ok(Some((result.into_py(py), code)))
+    }
+
+    /// Return the number of photons still pending in the work stack.
+    fn __len__(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Serialize the outstanding work stack, so that transport can be resumed later via
+    /// `TransportEngine.resume_iter`.
+    fn checkpoint<'py>(&self, py: Python<'py>) -> Result<&'py PyBytes> {
+        let mut buffer = Vec::new();
+        let mut serializer = Serializer::new(&mut buffer);
+        self.stack.serialize(&mut serializer)?;
+        Ok(PyBytes::new(py, &buffer))
     }
 }
 
@@ -624,7 +1283,7 @@ impl PyTransportEngine {
 // C representation of a photon state.
 // ===============================================================================================
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub(crate) struct CState {
     pub energy: Float,
     pub position: [Float; 3],
@@ -710,6 +1369,14 @@ pub(crate) struct PyTransportStatus ();
 #[allow(non_snake_case)]
 #[pymethods]
 impl PyTransportStatus {
+    /// Sentinel status for photons left untransported after a `progress` callback aborted the
+    /// run. It has no counterpart in the core `TransportStatus` enum.
+    #[classattr]
+    fn ABORTED(py: Python<'_>) -> Result<PyObject> {
+        let scalar = PyScalar::new(py, ABORTED_STATUS)?;
+        Ok(scalar.into())
+    }
+
     #[classattr]
     fn ABSORBED(py: Python<'_>) -> Result<PyObject> {
         Self::into_i32(py, TransportStatus::Absorbed)
@@ -748,9 +1415,39 @@ impl PyTransportStatus {
     /// Return the string representation of a `TransportStatus` integer code.
     #[staticmethod]
     fn str(code: i32) -> Result<String> {
+        if code == ABORTED_STATUS {
+            return Ok("Aborted".to_string());
+        }
         let status: TransportStatus = code.try_into()?;
         Ok(status.into())
     }
+
+    /// Return the integer code of a status, given either its code or its (case insensitive)
+    /// symbolic name, e.g. `TransportStatus.parse("absorbed")`.
+    #[staticmethod]
+    fn parse(value: StatusArg) -> Result<i32> {
+        match value {
+            StatusArg::Code(code) => {
+                Self::str(code)?; // Checks that the code is valid.
+                Ok(code)
+            },
+            StatusArg::Name(name) => {
+                for (candidate, code) in status_codes() {
+                    if candidate.eq_ignore_ascii_case(name) {
+                        return Ok(code);
+                    }
+                }
+                value_error!("bad status (unknown name '{}')", name)
+            },
+        }
+    }
+
+    /// Return the `{name: code}` mapping of every `TransportStatus` variant, including the
+    /// `ABORTED` sentinel.
+    #[classattr]
+    fn NAMES(py: Python) -> Result<PyObject> {
+        status_mapping(py)
+    }
 }
 
 impl PyTransportStatus {
@@ -760,3 +1457,82 @@ impl PyTransportStatus {
         Ok(scalar.into())
     }
 }
+
+// Status code for photons left untransported after a `progress` callback requested an abort.
+// Chosen outside of the core `TransportStatus` codomain (which is non-negative) so it can never
+// collide with a real status.
+pub(crate) const ABORTED_STATUS: i32 = -1;
+
+// Every `(name, code)` pair, including the `ABORTED` sentinel, backing `TransportStatus.parse`,
+// `TransportStatus.NAMES` and the module-level `TRANSPORT_STATUS` mapping.
+fn status_codes() -> [(&'static str, i32); 8] {
+    fn code_of(status: TransportStatus) -> i32 {
+        status.into()
+    }
+
+    [
+        ("Absorbed", code_of(TransportStatus::Absorbed)),
+        ("Boundary", code_of(TransportStatus::Boundary)),
+        ("EnergyConstraint", code_of(TransportStatus::EnergyConstraint)),
+        ("EnergyMax", code_of(TransportStatus::EnergyMax)),
+        ("EnergyMin", code_of(TransportStatus::EnergyMin)),
+        ("Exit", code_of(TransportStatus::Exit)),
+        ("LengthMax", code_of(TransportStatus::LengthMax)),
+        ("Aborted", ABORTED_STATUS),
+    ]
+}
+
+// Builds the `{name: code}` mapping exposed at module scope as `TRANSPORT_STATUS`, so that Python
+// code can introspect the full set of symbolic status names without hard-coding them.
+pub(crate) fn status_mapping(py: Python) -> Result<PyObject> {
+    let mapping = PyDict::new(py);
+    for (name, code) in status_codes() {
+        mapping.set_item(name, code)?;
+    }
+    Ok(mapping.into())
+}
+
+// A lock-free histogram over every known `TransportStatus` code (including the `ABORTED`
+// sentinel), accumulated inline during a transport run and reported back to Python as a `{name:
+// count}` dict.
+struct StatusTally([AtomicUsize; 8]);
+
+impl StatusTally {
+    fn new() -> Self {
+        Self(std::array::from_fn(|_| AtomicUsize::new(0)))
+    }
+
+    fn record(&self, code: i32) {
+        for (i, (_, candidate)) in status_codes().iter().enumerate() {
+            if *candidate == code {
+                self.0[i].fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+
+    fn into_dict(self, py: Python) -> Result<PyObject> {
+        let dict = PyDict::new(py);
+        for (i, (name, _)) in status_codes().iter().enumerate() {
+            dict.set_item(*name, self.0[i].load(Ordering::Relaxed))?;
+        }
+        Ok(dict.into())
+    }
+}
+
+// Either representation of a `TransportStatus`, accepted wherever a status is expected from
+// Python: its integer code, or its (case insensitive) symbolic name.
+pub(crate) enum StatusArg<'a> {
+    Code(i32),
+    Name(&'a str),
+}
+
+impl<'a> FromPyObject<'a> for StatusArg<'a> {
+    fn extract(obj: &'a PyAny) -> PyResult<Self> {
+        if let Ok(code) = obj.extract::<i32>() {
+            Ok(Self::Code(code))
+        } else {
+            Ok(Self::Name(obj.extract::<&'a str>()?))
+        }
+    }
+}